@@ -7,14 +7,113 @@ use crate::error::{Error, Result};
 /// We use two zero-width characters to represent binary data:
 /// - U+200B (ZERO WIDTH SPACE) represents binary '0'
 /// - U+200C (ZERO WIDTH NON-JOINER) represents binary '1'
-const ZERO_BIT: char = '\u{200B}'; // ZERO WIDTH SPACE
-const ONE_BIT: char = '\u{200C}'; // ZERO WIDTH NON-JOINER
+pub(crate) const ZERO_BIT: char = '\u{200B}'; // ZERO WIDTH SPACE
+pub(crate) const ONE_BIT: char = '\u{200C}'; // ZERO WIDTH NON-JOINER
 
 /// Marker to indicate the start of the hidden message.
-const START_MARKER: &str = "\u{200D}"; // ZERO WIDTH JOINER
+pub(crate) const START_MARKER: &str = "\u{200D}"; // ZERO WIDTH JOINER
 
 /// Marker to indicate the end of the hidden message.
-const END_MARKER: &str = "\u{FEFF}"; // ZERO WIDTH NO-BREAK SPACE
+pub(crate) const END_MARKER: &str = "\u{FEFF}"; // ZERO WIDTH NO-BREAK SPACE
+
+/// Number of bytes occupied by the CRC-24 checksum appended by
+/// [`encode_checked`].
+const CHECKSUM_LEN: usize = 3;
+
+/// Computes the OpenPGP CRC-24 checksum (as used by ASCII armor) over `bytes`.
+///
+/// The register is initialized to `0xB704CE`. Each payload byte is XORed
+/// into the top 8 bits of the register, followed by 8 rounds of
+/// `crc <<= 1; if crc & 0x1000000 != 0 { crc ^= 0x864CFB }`, masking to
+/// 24 bits after every round.
+fn crc24(bytes: &[u8]) -> u32 {
+    const INIT: u32 = 0xB704CE;
+    const POLY: u32 = 0x864CFB;
+
+    let mut crc = INIT;
+    for &byte in bytes {
+        crc ^= (byte as u32) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x1000000 != 0 {
+                crc ^= POLY;
+            }
+            crc &= 0xFFFFFF;
+        }
+    }
+    crc
+}
+
+/// Appends the zero-width bit representation of `byte` to `hidden`, MSB first.
+pub(crate) fn push_byte_bits(hidden: &mut String, byte: u8) {
+    for bit_pos in (0..8).rev() {
+        let bit = (byte >> bit_pos) & 1;
+        hidden.push(if bit == 1 { ONE_BIT } else { ZERO_BIT });
+    }
+}
+
+/// Decodes the zero-width bits between the start and end markers into bytes.
+///
+/// Returns `Error::CorruptedPayload` if the bit count isn't a whole number
+/// of bytes.
+fn decode_bits(hidden_section: &str) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut current_byte = 0u8;
+    let mut bit_count = 0;
+
+    for ch in hidden_section.chars() {
+        let bit = match ch {
+            ZERO_BIT => 0,
+            ONE_BIT => 1,
+            _ => continue, // Ignore non-encoding characters
+        };
+
+        current_byte = (current_byte << 1) | bit;
+        bit_count += 1;
+
+        if bit_count == 8 {
+            bytes.push(current_byte);
+            current_byte = 0;
+            bit_count = 0;
+        }
+    }
+
+    if bit_count != 0 {
+        return Err(Error::CorruptedPayload);
+    }
+
+    Ok(bytes)
+}
+
+/// Inserts `hidden` (the zero-width payload, including markers) after the
+/// first character of `cover_text`.
+fn splice_after_first_char(cover_text: &str, hidden: &str) -> String {
+    let mut chars = cover_text.chars();
+    let mut result = String::new();
+
+    if let Some(first_char) = chars.next() {
+        result.push(first_char);
+        result.push_str(hidden);
+        result.extend(chars);
+    }
+
+    result
+}
+
+/// Finds the hidden section between the start and end markers.
+fn find_hidden_section(encoded_text: &str) -> Result<&str> {
+    let start_pos = encoded_text.find(START_MARKER);
+    let end_pos = encoded_text.find(END_MARKER);
+
+    match (start_pos, end_pos) {
+        (Some(start), Some(end)) if start < end => {
+            let hidden_start = start + START_MARKER.len();
+            Ok(&encoded_text[hidden_start..end])
+        }
+        (Some(_), Some(_)) => Err(Error::CorruptedPayload),
+        _ => Err(Error::NoHiddenMessage),
+    }
+}
 
 /// Encodes a secret message into cover text using zero-width Unicode characters.
 ///
@@ -52,25 +151,51 @@ pub fn encode(cover_text: &str, secret: &str) -> Result<String> {
     let mut hidden = String::from(START_MARKER);
 
     for &byte in secret_bytes {
-        for bit_pos in (0..8).rev() {
-            let bit = (byte >> bit_pos) & 1;
-            hidden.push(if bit == 1 { ONE_BIT } else { ZERO_BIT });
-        }
+        push_byte_bits(&mut hidden, byte);
     }
 
     hidden.push_str(END_MARKER);
 
-    // Insert hidden message after the first character of cover text
-    let mut chars = cover_text.chars();
-    let mut result = String::new();
+    Ok(splice_after_first_char(cover_text, &hidden))
+}
 
-    if let Some(first_char) = chars.next() {
-        result.push(first_char);
-        result.push_str(&hidden);
-        result.extend(chars);
+/// Encodes a secret message into cover text, appending a CRC-24 checksum
+/// over the secret bytes so tampering can be detected on decode.
+///
+/// This uses the same zero-width framing as [`encode`], with the checksum
+/// bits placed after the message bits and before the end marker. Payloads
+/// produced by `encode` cannot be read by [`decode_checked`] and vice versa,
+/// since the checksum changes the number of bits between the markers.
+///
+/// # Example
+///
+/// ```
+/// use whisper_text::{encode_checked, decode_checked};
+///
+/// let encoded = encode_checked("Hello, World!", "secret").unwrap();
+/// let decoded = decode_checked(&encoded).unwrap();
+/// assert_eq!(decoded, "secret");
+/// ```
+pub fn encode_checked(cover_text: &str, secret: &str) -> Result<String> {
+    if cover_text.is_empty() {
+        return Err(Error::CoverTextTooShort);
     }
 
-    Ok(result)
+    let secret_bytes = secret.as_bytes();
+    let checksum = crc24(secret_bytes);
+
+    let mut hidden = String::from(START_MARKER);
+
+    for &byte in secret_bytes {
+        push_byte_bits(&mut hidden, byte);
+    }
+    for byte_pos in (0..CHECKSUM_LEN).rev() {
+        push_byte_bits(&mut hidden, (checksum >> (byte_pos * 8)) as u8);
+    }
+
+    hidden.push_str(END_MARKER);
+
+    Ok(splice_after_first_char(cover_text, &hidden))
 }
 
 /// Decodes a hidden message from text containing zero-width Unicode characters.
@@ -98,54 +223,370 @@ pub fn encode(cover_text: &str, secret: &str) -> Result<String> {
 /// assert_eq!(decoded, "secret");
 /// ```
 pub fn decode(encoded_text: &str) -> Result<String> {
-    // Find start and end markers
-    let start_pos = encoded_text.find(START_MARKER);
-    let end_pos = encoded_text.find(END_MARKER);
+    let hidden_section = find_hidden_section(encoded_text)?;
+    let bytes = decode_bits(hidden_section)?;
 
-    match (start_pos, end_pos) {
-        (Some(start), Some(end)) if start < end => {
-            // Extract the hidden section (between markers)
-            let hidden_start = start + START_MARKER.len();
-            let hidden_section = &encoded_text[hidden_start..end];
-
-            // Decode the binary data
-            let mut bytes = Vec::new();
-            let mut current_byte = 0u8;
-            let mut bit_count = 0;
-
-            for ch in hidden_section.chars() {
-                let bit = match ch {
-                    ZERO_BIT => 0,
-                    ONE_BIT => 1,
-                    _ => continue, // Ignore non-encoding characters
-                };
-
-                current_byte = (current_byte << 1) | bit;
-                bit_count += 1;
-
-                if bit_count == 8 {
-                    bytes.push(current_byte);
-                    current_byte = 0;
-                    bit_count = 0;
-                }
-            }
+    String::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)
+}
 
-            // Check if we have incomplete bits (corruption)
-            if bit_count != 0 {
-                return Err(Error::CorruptedPayload);
-            }
+/// Decodes a hidden message produced by [`encode_checked`], verifying its
+/// CRC-24 checksum.
+///
+/// Returns `Error::ChecksumMismatch` if the decoded bytes don't match the
+/// embedded checksum, which catches corruption that keeps the bit count
+/// aligned to a whole number of bytes (and so would otherwise decode
+/// silently, or only fail once the bytes are interpreted as UTF-8).
+///
+/// # Example
+///
+/// ```
+/// use whisper_text::{encode_checked, decode_checked};
+///
+/// let encoded = encode_checked("Hello, World!", "secret").unwrap();
+/// let decoded = decode_checked(&encoded).unwrap();
+/// assert_eq!(decoded, "secret");
+/// ```
+pub fn decode_checked(encoded_text: &str) -> Result<String> {
+    let hidden_section = find_hidden_section(encoded_text)?;
+    let mut bytes = decode_bits(hidden_section)?;
+
+    if bytes.len() < CHECKSUM_LEN {
+        return Err(Error::CorruptedPayload);
+    }
+
+    let message_len = bytes.len() - CHECKSUM_LEN;
+    let checksum_bytes = bytes.split_off(message_len);
+    let expected = crc24(&bytes);
+    let actual = checksum_bytes
+        .iter()
+        .fold(0u32, |acc, &b| (acc << 8) | b as u32);
 
-            // Convert bytes to string
-            String::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)
+    if actual != expected {
+        return Err(Error::ChecksumMismatch);
+    }
+
+    String::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)
+}
+
+/// Encodes a secret message into cover text, encrypting it first with a key
+/// derived from `passphrase`.
+///
+/// Unlike [`encode`], which only hides the message, this also protects its
+/// confidentiality: recovering the secret requires both finding the
+/// zero-width payload and knowing the passphrase. The encrypted blob
+/// (salt, nonce, ciphertext and authentication tag) is embedded using the
+/// same zero-width framing as `encode`.
+///
+/// # Example
+///
+/// ```
+/// use whisper_text::{encode_encrypted, decode_encrypted};
+///
+/// let encoded = encode_encrypted("Hello, World!", "secret", "correct horse").unwrap();
+/// let decoded = decode_encrypted(&encoded, "correct horse").unwrap();
+/// assert_eq!(decoded, "secret");
+/// ```
+pub fn encode_encrypted(cover_text: &str, secret: &str, passphrase: &str) -> Result<String> {
+    if cover_text.is_empty() {
+        return Err(Error::CoverTextTooShort);
+    }
+
+    let blob = crate::crypto::encrypt(passphrase, secret.as_bytes());
+
+    let mut hidden = String::from(START_MARKER);
+    for byte in blob {
+        push_byte_bits(&mut hidden, byte);
+    }
+    hidden.push_str(END_MARKER);
+
+    Ok(splice_after_first_char(cover_text, &hidden))
+}
+
+/// Decodes a hidden message produced by [`encode_encrypted`], decrypting it
+/// with a key derived from `passphrase`.
+///
+/// Returns `Error::DecryptionFailed` if the passphrase is wrong or the
+/// embedded blob was tampered with or truncated.
+///
+/// # Example
+///
+/// ```
+/// use whisper_text::{encode_encrypted, decode_encrypted};
+///
+/// let encoded = encode_encrypted("Hello, World!", "secret", "correct horse").unwrap();
+/// let decoded = decode_encrypted(&encoded, "correct horse").unwrap();
+/// assert_eq!(decoded, "secret");
+/// ```
+pub fn decode_encrypted(encoded_text: &str, passphrase: &str) -> Result<String> {
+    let hidden_section = find_hidden_section(encoded_text)?;
+    let blob = decode_bits(hidden_section)?;
+    let plaintext = crate::crypto::decrypt(passphrase, &blob)?;
+
+    String::from_utf8(plaintext).map_err(|_| Error::InvalidUtf8)
+}
+
+/// Number of bytes used for the message-kind tag written by
+/// [`encode_typed`], ahead of the payload bytes.
+const KIND_LEN: usize = 2;
+
+/// Encodes a typed payload into cover text: a 16-bit "kind" tag followed by
+/// raw bytes, both embedded with the same zero-width framing as [`encode`].
+///
+/// The kind lets a single cover text carry more than raw bytes — callers
+/// agree on kind values for their own payload types (UTF-8 text, a URL, a
+/// file blob, ...) and decode them via [`decode_typed`] or, for a typed
+/// result, [`crate::MessageReader`].
+///
+/// # Example
+///
+/// ```
+/// use whisper_text::{encode_typed, decode_typed};
+///
+/// let encoded = encode_typed("Hello, World!", 1, b"secret").unwrap();
+/// let (kind, bytes) = decode_typed(&encoded).unwrap();
+/// assert_eq!(kind, 1);
+/// assert_eq!(bytes, b"secret");
+/// ```
+pub fn encode_typed(cover_text: &str, kind: u16, bytes: &[u8]) -> Result<String> {
+    if cover_text.is_empty() {
+        return Err(Error::CoverTextTooShort);
+    }
+
+    let mut hidden = String::from(START_MARKER);
+    push_byte_bits(&mut hidden, (kind >> 8) as u8);
+    push_byte_bits(&mut hidden, kind as u8);
+    for &byte in bytes {
+        push_byte_bits(&mut hidden, byte);
+    }
+    hidden.push_str(END_MARKER);
+
+    Ok(splice_after_first_char(cover_text, &hidden))
+}
+
+/// Decodes a typed payload embedded by [`encode_typed`], returning its kind
+/// and raw bytes.
+///
+/// An unrecognized kind still round-trips as opaque bytes, so the format
+/// stays forward-compatible with readers that don't know every kind.
+///
+/// # Example
+///
+/// ```
+/// use whisper_text::{encode_typed, decode_typed};
+///
+/// let encoded = encode_typed("Hello, World!", 1, b"secret").unwrap();
+/// let (kind, bytes) = decode_typed(&encoded).unwrap();
+/// assert_eq!(kind, 1);
+/// assert_eq!(bytes, b"secret");
+/// ```
+pub fn decode_typed(encoded_text: &str) -> Result<(u16, Vec<u8>)> {
+    let hidden_section = find_hidden_section(encoded_text)?;
+    let mut bytes = decode_bits(hidden_section)?;
+
+    if bytes.len() < KIND_LEN {
+        return Err(Error::CorruptedPayload);
+    }
+
+    let payload = bytes.split_off(KIND_LEN);
+    let kind = ((bytes[0] as u16) << 8) | bytes[1] as u16;
+    Ok((kind, payload))
+}
+
+/// The four zero-width characters used by the base-4 ("dense") encoding,
+/// indexed by their 2-bit symbol value: `00`, `01`, `10`, `11`.
+const DENSE_ALPHABET: [char; 4] = [ZERO_BIT, ONE_BIT, '\u{200D}', '\u{FEFF}'];
+
+/// A fixed marker byte written before the length prefix in dense-encoded
+/// payloads, letting [`decode_dense`] recognize its own framing (and
+/// reject plain [`encode`] output, whose first symbols are unlikely to
+/// match it) without relying on sentinel characters, since dense encoding
+/// uses every zero-width character as a data symbol.
+const DENSE_PREAMBLE_BYTE: u8 = 0xA5;
+
+/// Looks up the 2-bit value of a dense alphabet symbol.
+fn dense_symbol_value(ch: char) -> Option<u8> {
+    DENSE_ALPHABET.iter().position(|&sym| sym == ch).map(|i| i as u8)
+}
+
+/// Appends the dense (2-bit-per-char) representation of `byte` to `hidden`,
+/// most significant pair of bits first.
+fn push_byte_dense(hidden: &mut String, byte: u8) {
+    for shift in [6, 4, 2, 0] {
+        hidden.push(DENSE_ALPHABET[((byte >> shift) & 0b11) as usize]);
+    }
+}
+
+/// Appends the dense representation of `value`, most significant byte
+/// first.
+fn push_u32_dense(hidden: &mut String, value: u32) {
+    for shift in [24, 16, 8, 0] {
+        push_byte_dense(hidden, (value >> shift) as u8);
+    }
+}
+
+/// Reads `byte_count` dense-encoded bytes (4 symbols each) from `chars`.
+///
+/// Returns `Error::CorruptedPayload` if the stream ends early or contains a
+/// character outside the dense alphabet.
+fn read_dense_bytes(chars: &mut impl Iterator<Item = char>, byte_count: usize) -> Result<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(byte_count);
+
+    for _ in 0..byte_count {
+        let mut byte = 0u8;
+        for _ in 0..4 {
+            let ch = chars.next().ok_or(Error::CorruptedPayload)?;
+            let value = dense_symbol_value(ch).ok_or(Error::CorruptedPayload)?;
+            byte = (byte << 2) | value;
         }
-        (Some(_), Some(_)) => Err(Error::CorruptedPayload),
-        _ => Err(Error::NoHiddenMessage),
+        bytes.push(byte);
+    }
+
+    Ok(bytes)
+}
+
+/// Encodes a secret message using all four recognized zero-width
+/// characters as a base-4 alphabet, packing 2 bits per hidden character
+/// instead of 1.
+///
+/// This roughly halves the number of zero-width characters needed per
+/// secret byte compared to [`encode`], improving stealth against
+/// length-based detection. Because every zero-width character is now a
+/// data symbol, there's no room for sentinel start/end markers; instead
+/// the hidden block opens with a fixed preamble byte and a 4-byte length
+/// prefix (both dense-encoded) so [`decode_dense`] knows exactly how many
+/// symbols to read.
+///
+/// # Example
+///
+/// ```
+/// use whisper_text::{encode_dense, decode_dense};
+///
+/// let encoded = encode_dense("Hello, World!", "secret").unwrap();
+/// let decoded = decode_dense(&encoded).unwrap();
+/// assert_eq!(decoded, "secret");
+/// ```
+pub fn encode_dense(cover_text: &str, secret: &str) -> Result<String> {
+    if cover_text.is_empty() {
+        return Err(Error::CoverTextTooShort);
+    }
+
+    let secret_bytes = secret.as_bytes();
+
+    let mut hidden = String::new();
+    push_byte_dense(&mut hidden, DENSE_PREAMBLE_BYTE);
+    push_u32_dense(&mut hidden, secret_bytes.len() as u32);
+    for &byte in secret_bytes {
+        push_byte_dense(&mut hidden, byte);
+    }
+
+    Ok(splice_after_first_char(cover_text, &hidden))
+}
+
+/// Decodes a secret message embedded by [`encode_dense`].
+///
+/// Returns `Error::NoHiddenMessage` if the text doesn't open with the dense
+/// preamble right after its first character, or `Error::CorruptedPayload`
+/// if fewer dense symbols follow than the length prefix claims. Symbols
+/// beyond the claimed length are not read.
+///
+/// # Example
+///
+/// ```
+/// use whisper_text::{encode_dense, decode_dense};
+///
+/// let encoded = encode_dense("Hello, World!", "secret").unwrap();
+/// let decoded = decode_dense(&encoded).unwrap();
+/// assert_eq!(decoded, "secret");
+/// ```
+pub fn decode_dense(encoded_text: &str) -> Result<String> {
+    let mut chars = encoded_text.chars();
+    chars.next(); // the cover's first, unmodified character
+
+    let preamble = read_dense_bytes(&mut chars, 1).map_err(|_| Error::NoHiddenMessage)?;
+    if preamble[0] != DENSE_PREAMBLE_BYTE {
+        return Err(Error::NoHiddenMessage);
+    }
+
+    let len_bytes = read_dense_bytes(&mut chars, 4)?;
+    let len = u32::from_be_bytes([len_bytes[0], len_bytes[1], len_bytes[2], len_bytes[3]]) as usize;
+
+    // Validate the claimed length against what's actually left before
+    // allocating for it, so a crafted, truncated length prefix can't force
+    // a multi-gigabyte allocation from a handful of input bytes.
+    let remaining_symbols = chars.clone().count();
+    if remaining_symbols < len.saturating_mul(4) {
+        return Err(Error::CorruptedPayload);
     }
+
+    let bytes = read_dense_bytes(&mut chars, len)?;
+    String::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)
+}
+
+/// Maximum number of zero-width symbols [`encode_dispersed`] will place in
+/// any single gap between cover characters.
+const DISPERSED_MAX_PER_GAP: usize = 3;
+
+/// Encodes a secret message into cover text, spreading the hidden
+/// zero-width symbols evenly across the gaps between the cover's visible
+/// characters instead of clustering them all after the first character.
+///
+/// This defeats detection heuristics that look for one long run of
+/// zero-width characters (e.g. a hex dump, or a "count consecutive
+/// zero-width chars" scan). The payload still uses the same start/end
+/// markers as [`encode`], so [`decode`] reads it back unchanged regardless
+/// of how the symbols end up spaced out.
+///
+/// Returns `Error::CoverTextTooShort` if the cover doesn't have enough
+/// gaps between characters to hold the payload without exceeding
+/// `DISPERSED_MAX_PER_GAP` symbols per gap.
+///
+/// # Example
+///
+/// ```
+/// use whisper_text::{encode_dispersed, decode};
+///
+/// let cover = "The quick brown fox jumps over the lazy dog.";
+/// let encoded = encode_dispersed(cover, "secret").unwrap();
+/// assert_eq!(decode(&encoded).unwrap(), "secret");
+/// ```
+pub fn encode_dispersed(cover_text: &str, secret: &str) -> Result<String> {
+    let cover_chars: Vec<char> = cover_text.chars().collect();
+    let gap_count = cover_chars.len().saturating_sub(1);
+
+    let mut hidden = String::from(START_MARKER);
+    for &byte in secret.as_bytes() {
+        push_byte_bits(&mut hidden, byte);
+    }
+    hidden.push_str(END_MARKER);
+    let symbols: Vec<char> = hidden.chars().collect();
+
+    let required_gaps = symbols.len().div_ceil(DISPERSED_MAX_PER_GAP);
+    if gap_count < required_gaps {
+        return Err(Error::CoverTextTooShort);
+    }
+
+    // Assign each symbol to a gap in order, spreading them as evenly as
+    // possible across the available gaps.
+    let mut gaps: Vec<String> = vec![String::new(); gap_count];
+    for (i, &symbol) in symbols.iter().enumerate() {
+        gaps[i * gap_count / symbols.len()].push(symbol);
+    }
+
+    let mut result = String::new();
+    for (i, &ch) in cover_chars.iter().enumerate() {
+        result.push(ch);
+        if i < gap_count {
+            result.push_str(&gaps[i]);
+        }
+    }
+
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::is_zero_width_char;
 
     #[test]
     fn test_encode_basic() {
@@ -249,6 +690,257 @@ mod tests {
         assert_eq!(visible, cover);
     }
 
+    #[test]
+    fn test_round_trip_checked() {
+        let cover = "Hello, World!";
+        let secret = "secret";
+
+        let encoded = encode_checked(cover, secret).unwrap();
+        let decoded = decode_checked(&encoded).unwrap();
+
+        assert_eq!(decoded, secret);
+    }
+
+    #[test]
+    fn test_checked_rejects_plain_encode() {
+        let cover = "Hello, World!";
+        let secret = "secret";
+
+        let encoded = encode(cover, secret).unwrap();
+        let result = decode_checked(&encoded);
+
+        assert!(matches!(
+            result,
+            Err(Error::ChecksumMismatch) | Err(Error::CorruptedPayload)
+        ));
+    }
+
+    #[test]
+    fn test_checksum_mismatch_on_tampered_bit() {
+        let cover = "Hello, World!";
+        let secret = "secret";
+
+        let encoded = encode_checked(cover, secret).unwrap();
+
+        // Flip one encoded bit without changing the bit count, simulating
+        // corruption that would otherwise decode silently.
+        let mut done = false;
+        let flipped: String = encoded
+            .chars()
+            .map(|c| {
+                if c == ZERO_BIT && !done {
+                    done = true;
+                    ONE_BIT
+                } else {
+                    c
+                }
+            })
+            .collect();
+
+        let result = decode_checked(&flipped);
+        assert_eq!(result, Err(Error::ChecksumMismatch));
+    }
+
+    #[test]
+    fn test_crc24_known_vector() {
+        // CRC-24 of the empty input is the initialization value.
+        assert_eq!(crc24(&[]), 0xB704CE);
+    }
+
+    #[test]
+    fn test_round_trip_encrypted() {
+        let cover = "Hello, World!";
+        let secret = "secret";
+
+        let encoded = encode_encrypted(cover, secret, "correct horse").unwrap();
+        let decoded = decode_encrypted(&encoded, "correct horse").unwrap();
+
+        assert_eq!(decoded, secret);
+    }
+
+    #[test]
+    fn test_encrypted_wrong_passphrase() {
+        let cover = "Hello, World!";
+        let secret = "secret";
+
+        let encoded = encode_encrypted(cover, secret, "correct horse").unwrap();
+        let result = decode_encrypted(&encoded, "wrong passphrase");
+
+        assert_eq!(result, Err(Error::DecryptionFailed));
+    }
+
+    #[test]
+    fn test_encrypted_hides_plaintext() {
+        let cover = "Hello, World!";
+        let secret = "a very identifiable secret string";
+
+        let encoded = encode_encrypted(cover, secret, "correct horse").unwrap();
+
+        assert!(!encoded.contains(secret));
+    }
+
+    #[test]
+    fn test_round_trip_typed() {
+        let cover = "Hello, World!";
+
+        let encoded = encode_typed(cover, 42, b"secret").unwrap();
+        let (kind, bytes) = decode_typed(&encoded).unwrap();
+
+        assert_eq!(kind, 42);
+        assert_eq!(bytes, b"secret");
+    }
+
+    #[test]
+    fn test_typed_empty_payload() {
+        let cover = "Hello, World!";
+
+        let encoded = encode_typed(cover, 0, b"").unwrap();
+        let (kind, bytes) = decode_typed(&encoded).unwrap();
+
+        assert_eq!(kind, 0);
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn test_typed_unknown_kind_round_trips_as_opaque_bytes() {
+        let cover = "Hello, World!";
+
+        let encoded = encode_typed(cover, 0xBEEF, b"raw data").unwrap();
+        let (kind, bytes) = decode_typed(&encoded).unwrap();
+
+        assert_eq!(kind, 0xBEEF);
+        assert_eq!(bytes, b"raw data");
+    }
+
+    #[test]
+    fn test_round_trip_dense() {
+        let cover = "Hello, World!";
+        let secret = "secret";
+
+        let encoded = encode_dense(cover, secret).unwrap();
+        let decoded = decode_dense(&encoded).unwrap();
+
+        assert_eq!(decoded, secret);
+    }
+
+    #[test]
+    fn test_round_trip_dense_unicode() {
+        let cover = "Hello, 世界! 👋";
+        let secret = "Unicode: 你好 🚀";
+
+        let encoded = encode_dense(cover, secret).unwrap();
+        let decoded = decode_dense(&encoded).unwrap();
+
+        assert_eq!(decoded, secret);
+    }
+
+    #[test]
+    fn test_round_trip_dense_empty_secret() {
+        let cover = "Cover";
+        let secret = "";
+
+        let encoded = encode_dense(cover, secret).unwrap();
+        let decoded = decode_dense(&encoded).unwrap();
+
+        assert_eq!(decoded, secret);
+    }
+
+    #[test]
+    fn test_dense_uses_fewer_hidden_chars_than_bit_per_char() {
+        let cover = "Hello, World!";
+        let secret = "a reasonably long secret message";
+
+        let dense = encode_dense(cover, secret).unwrap();
+        let sparse = encode(cover, secret).unwrap();
+
+        let count_hidden = |s: &str| s.chars().filter(|&c| is_zero_width_char(c)).count();
+
+        assert!(count_hidden(&dense) < count_hidden(&sparse));
+    }
+
+    #[test]
+    fn test_decode_dense_rejects_bit_per_char_encoding() {
+        let cover = "Hello, World!";
+        let secret = "secret";
+
+        let encoded = encode(cover, secret).unwrap();
+        let result = decode_dense(&encoded);
+
+        assert_eq!(result, Err(Error::NoHiddenMessage));
+    }
+
+    #[test]
+    fn test_decode_dense_no_hidden_message() {
+        let result = decode_dense("just plain text");
+        assert_eq!(result, Err(Error::NoHiddenMessage));
+    }
+
+    #[test]
+    fn test_decode_dense_truncated_payload() {
+        // Use a single-character cover so the hidden block is the tail of
+        // the string, and truncating the string truncates the payload.
+        let cover = "H";
+        let secret = "a longer secret than the truncated text can hold";
+
+        let encoded = encode_dense(cover, secret).unwrap();
+        let truncated: String = encoded.chars().take(encoded.chars().count() - 4).collect();
+
+        assert_eq!(decode_dense(&truncated), Err(Error::CorruptedPayload));
+    }
+
+    #[test]
+    fn test_round_trip_dispersed() {
+        let cover = "The quick brown fox jumps over the lazy dog.";
+        let secret = "secret message!";
+
+        let encoded = encode_dispersed(cover, secret).unwrap();
+        let decoded = decode(&encoded).unwrap();
+
+        assert_eq!(decoded, secret);
+    }
+
+    #[test]
+    fn test_dispersed_avoids_one_big_cluster() {
+        let cover = "The quick brown fox jumps over the lazy dog.";
+        let secret = "secret message!";
+
+        let encoded = encode_dispersed(cover, secret).unwrap();
+
+        let mut longest_run = 0;
+        let mut current_run = 0;
+        for ch in encoded.chars() {
+            if is_zero_width_char(ch) {
+                current_run += 1;
+                longest_run = longest_run.max(current_run);
+            } else {
+                current_run = 0;
+            }
+        }
+
+        assert!(longest_run <= DISPERSED_MAX_PER_GAP);
+    }
+
+    #[test]
+    fn test_dispersed_cover_too_short() {
+        let cover = "Hi";
+        let secret = "a secret message too long for such a short cover";
+
+        let result = encode_dispersed(cover, secret);
+
+        assert_eq!(result, Err(Error::CoverTextTooShort));
+    }
+
+    #[test]
+    fn test_dispersed_preserves_visible_content() {
+        let cover = "The quick brown fox jumps over the lazy dog.";
+        let secret = "secret";
+
+        let encoded = encode_dispersed(cover, secret).unwrap();
+        let visible: String = encoded.chars().filter(|&c| !is_zero_width_char(c)).collect();
+
+        assert_eq!(visible, cover);
+    }
+
     #[test]
     fn test_multiple_messages() {
         let cover = "Test";
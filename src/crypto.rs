@@ -0,0 +1,119 @@
+//! Password-based authenticated encryption for hidden payloads.
+//!
+//! Secrets are encrypted with AES-256-GCM using a key derived from the
+//! caller's passphrase via Argon2id, so a message stays confidential even
+//! if the zero-width embedding itself is discovered.
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use argon2::Argon2;
+use rand::RngCore;
+
+use crate::error::{Error, Result};
+
+/// Length in bytes of the random Argon2 salt.
+pub(crate) const SALT_LEN: usize = 16;
+
+/// Length in bytes of the AES-GCM nonce.
+pub(crate) const NONCE_LEN: usize = 12;
+
+/// Length in bytes of the derived AES-256 key.
+const KEY_LEN: usize = 32;
+
+/// Derives a 256-bit key from `passphrase` and `salt` using Argon2id with
+/// its default parameters.
+fn derive_key(passphrase: &str, salt: &[u8]) -> [u8; KEY_LEN] {
+    let mut key = [0u8; KEY_LEN];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .expect("KEY_LEN is a valid Argon2 output length");
+    key
+}
+
+/// Encrypts `plaintext` with a key derived from `passphrase`.
+///
+/// Returns `salt ‖ nonce ‖ ciphertext ‖ tag`, using a fresh random salt and
+/// nonce for every call.
+pub(crate) fn encrypt(passphrase: &str, plaintext: &[u8]) -> Vec<u8> {
+    let mut salt = [0u8; SALT_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from(nonce_bytes);
+
+    let key = derive_key(passphrase, &salt);
+    let cipher = Aes256Gcm::new((&key).into());
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .expect("AES-256-GCM encryption does not fail for in-memory buffers");
+
+    let mut out = Vec::with_capacity(SALT_LEN + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce_bytes);
+    out.extend_from_slice(&ciphertext);
+    out
+}
+
+/// Decrypts a `salt ‖ nonce ‖ ciphertext ‖ tag` blob produced by [`encrypt`].
+///
+/// Returns `Error::DecryptionFailed` if the blob is too short to contain a
+/// salt and nonce, or if the authentication tag doesn't match (a wrong
+/// passphrase or tampered ciphertext).
+pub(crate) fn decrypt(passphrase: &str, blob: &[u8]) -> Result<Vec<u8>> {
+    if blob.len() < SALT_LEN + NONCE_LEN {
+        return Err(Error::DecryptionFailed);
+    }
+
+    let (salt, rest) = blob.split_at(SALT_LEN);
+    let (nonce_bytes, ciphertext) = rest.split_at(NONCE_LEN);
+    let nonce = Nonce::try_from(nonce_bytes).expect("NONCE_LEN matches Aes256Gcm's nonce size");
+
+    let key = derive_key(passphrase, salt);
+    let cipher = Aes256Gcm::new((&key).into());
+    cipher
+        .decrypt(&nonce, ciphertext)
+        .map_err(|_| Error::DecryptionFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let blob = encrypt("correct horse", b"secret message");
+        let decrypted = decrypt("correct horse", &blob).unwrap();
+        assert_eq!(decrypted, b"secret message");
+    }
+
+    #[test]
+    fn test_wrong_passphrase_fails() {
+        let blob = encrypt("correct horse", b"secret message");
+        let result = decrypt("wrong passphrase", &blob);
+        assert_eq!(result, Err(Error::DecryptionFailed));
+    }
+
+    #[test]
+    fn test_tampered_ciphertext_fails() {
+        let mut blob = encrypt("correct horse", b"secret message");
+        let last = blob.len() - 1;
+        blob[last] ^= 0xFF;
+
+        let result = decrypt("correct horse", &blob);
+        assert_eq!(result, Err(Error::DecryptionFailed));
+    }
+
+    #[test]
+    fn test_truncated_blob_fails() {
+        let result = decrypt("correct horse", &[0u8; 4]);
+        assert_eq!(result, Err(Error::DecryptionFailed));
+    }
+
+    #[test]
+    fn test_encrypt_is_randomized() {
+        let blob1 = encrypt("correct horse", b"secret message");
+        let blob2 = encrypt("correct horse", b"secret message");
+        assert_ne!(blob1, blob2);
+    }
+}
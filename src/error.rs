@@ -19,6 +19,15 @@ pub enum Error {
 
     /// Invalid UTF-8 encountered during decoding.
     InvalidUtf8,
+
+    /// The embedded checksum did not match the decoded payload, indicating
+    /// the hidden message was tampered with or corrupted in a way that
+    /// preserved bit alignment.
+    ChecksumMismatch,
+
+    /// Decryption failed: the passphrase was wrong, or the encrypted
+    /// payload was tampered with or truncated.
+    DecryptionFailed,
 }
 
 impl fmt::Display for Error {
@@ -36,6 +45,12 @@ impl fmt::Display for Error {
             Error::InvalidUtf8 => {
                 write!(f, "invalid UTF-8 encountered during decoding")
             }
+            Error::ChecksumMismatch => {
+                write!(f, "checksum mismatch: the hidden message was tampered with")
+            }
+            Error::DecryptionFailed => {
+                write!(f, "decryption failed: wrong passphrase or tampered payload")
+            }
         }
     }
 }
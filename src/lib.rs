@@ -22,7 +22,17 @@
 #![warn(missing_docs)]
 
 mod codec;
+mod crypto;
 mod error;
+mod message;
+mod stream;
+mod utils;
 
-pub use codec::{decode, encode};
+pub use codec::{
+    decode, decode_checked, decode_dense, decode_encrypted, decode_typed, encode, encode_checked,
+    encode_dense, encode_dispersed, encode_encrypted, encode_typed,
+};
 pub use error::{Error, Result};
+pub use message::{decode_as, MessageReader, KIND_RAW, KIND_TEXT, RESERVED_KIND_RANGE_END};
+pub use stream::{Reader, Writer};
+pub use utils::{is_zero_width_char, strip_hidden};
@@ -0,0 +1,93 @@
+//! A pluggable message-kind registry for typed payloads.
+//!
+//! [`MessageReader`] lets callers decode the raw bytes produced by
+//! [`crate::decode_typed`] into their own payload type (UTF-8 text, a URL,
+//! a file blob, ...), keyed by the kind tag written by
+//! [`crate::encode_typed`].
+
+use crate::error::Result;
+
+/// Upper bound (exclusive) of kind values reserved for this crate's own
+/// built-in uses. Callers defining their own [`MessageReader`] should pick
+/// kinds at or above this value, so future built-ins can claim lower ones
+/// without colliding.
+pub const RESERVED_KIND_RANGE_END: u16 = 16;
+
+/// Kind tag for an unstructured, opaque byte payload.
+pub const KIND_RAW: u16 = 0;
+
+/// Kind tag for a UTF-8 text payload.
+pub const KIND_TEXT: u16 = 1;
+
+/// Decodes the raw bytes of a typed payload into a caller-defined message
+/// type, keyed by its kind tag.
+///
+/// Implementors are handed every kind they're asked to decode, including
+/// ones they don't recognize — returning an error for unknown kinds, or
+/// passing them through as opaque data, is left to the implementation so
+/// the format stays forward-compatible.
+pub trait MessageReader: Sized {
+    /// The decoded message type produced by this reader.
+    type Message;
+
+    /// Decodes `bytes` tagged with `kind` into `Self::Message`.
+    fn read(kind: u16, bytes: &[u8]) -> Result<Self::Message>;
+}
+
+/// Decodes a typed payload from `text` (as embedded by
+/// [`crate::encode_typed`]) and dispatches it through `R`.
+pub fn decode_as<R: MessageReader>(text: &str) -> Result<R::Message> {
+    let (kind, bytes) = crate::codec::decode_typed(text)?;
+    R::read(kind, &bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::encode_typed;
+    use crate::error::Error;
+
+    enum Payload {
+        Text(String),
+        Opaque(u16, Vec<u8>),
+    }
+
+    struct Registry;
+
+    impl MessageReader for Registry {
+        type Message = Payload;
+
+        fn read(kind: u16, bytes: &[u8]) -> Result<Self::Message> {
+            match kind {
+                KIND_TEXT => {
+                    let text = String::from_utf8(bytes.to_vec()).map_err(|_| Error::InvalidUtf8)?;
+                    Ok(Payload::Text(text))
+                }
+                other => Ok(Payload::Opaque(other, bytes.to_vec())),
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_as_dispatches_known_kind() {
+        let encoded = encode_typed("Hello, World!", KIND_TEXT, b"secret").unwrap();
+
+        match decode_as::<Registry>(&encoded).unwrap() {
+            Payload::Text(text) => assert_eq!(text, "secret"),
+            Payload::Opaque(..) => panic!("expected a text payload"),
+        }
+    }
+
+    #[test]
+    fn test_decode_as_passes_through_unknown_kind() {
+        let encoded = encode_typed("Hello, World!", 0xBEEF, b"raw data").unwrap();
+
+        match decode_as::<Registry>(&encoded).unwrap() {
+            Payload::Opaque(kind, bytes) => {
+                assert_eq!(kind, 0xBEEF);
+                assert_eq!(bytes, b"raw data");
+            }
+            Payload::Text(_) => panic!("expected an opaque payload"),
+        }
+    }
+}
@@ -0,0 +1,420 @@
+//! Streaming encoder and decoder for embedding into large cover texts with
+//! bounded memory use.
+//!
+//! [`Writer`] and [`Reader`] mirror [`crate::encode`] and [`crate::decode`],
+//! but operate over [`std::io::Write`] and [`std::io::Read`] so cover text
+//! can be processed in chunks (e.g. hiding a message across an entire book)
+//! instead of being held as a single `String`.
+
+use std::io::{self, Read, Write};
+
+use crate::codec::{push_byte_bits, END_MARKER, ONE_BIT, START_MARKER, ZERO_BIT};
+use crate::error::Error;
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+fn io_err(err: Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Streaming encoder that interleaves a secret's zero-width bits into cover
+/// text as it is written, without buffering the whole cover in memory.
+///
+/// The hidden payload is still inserted right after the cover's first
+/// character, exactly as [`crate::encode`] does; only the cover text itself
+/// is streamed.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Write;
+/// use whisper_text::Writer;
+///
+/// let mut writer = Writer::new(Vec::new(), b"secret");
+/// writer.write_all(b"Hello, World!").unwrap();
+/// let encoded = writer.finish().unwrap();
+///
+/// let decoded = whisper_text::decode(&String::from_utf8(encoded).unwrap()).unwrap();
+/// assert_eq!(decoded, "secret");
+/// ```
+pub struct Writer<W: Write> {
+    inner: Option<W>,
+    hidden: Vec<u8>,
+    pending: Vec<u8>,
+    first_char_written: bool,
+}
+
+impl<W: Write> Writer<W> {
+    /// Creates a new streaming writer that will embed `secret` into the
+    /// cover text written through it.
+    pub fn new(inner: W, secret: &[u8]) -> Self {
+        let mut hidden = String::from(START_MARKER);
+        for &byte in secret {
+            push_byte_bits(&mut hidden, byte);
+        }
+        hidden.push_str(END_MARKER);
+
+        Writer {
+            inner: Some(inner),
+            hidden: hidden.into_bytes(),
+            pending: Vec::new(),
+            first_char_written: false,
+        }
+    }
+
+    /// Splits the first `char_len` bytes of `self.pending` off as the
+    /// cover's first character, writes it, then the hidden payload, then
+    /// the rest of what was buffered.
+    fn emit_first_char(&mut self, char_len: usize) -> io::Result<()> {
+        let rest = self.pending.split_off(char_len);
+        let inner = self.inner.as_mut().expect("writer already finished");
+        inner.write_all(&self.pending)?;
+        inner.write_all(&self.hidden)?;
+        inner.write_all(&rest)?;
+        self.pending.clear();
+        self.first_char_written = true;
+        Ok(())
+    }
+
+    /// Flushes any remaining buffered cover bytes and returns the inner
+    /// writer.
+    ///
+    /// Returns `Error::CoverTextTooShort` if no cover bytes were ever
+    /// written, matching [`crate::encode`]'s behavior on empty cover text.
+    pub fn finish(mut self) -> io::Result<W> {
+        if !self.first_char_written {
+            match std::str::from_utf8(&self.pending) {
+                Ok(s) if s.chars().next().is_some() => {
+                    let char_len = s.chars().next().unwrap().len_utf8();
+                    self.emit_first_char(char_len)?;
+                }
+                _ if self.pending.is_empty() => return Err(io_err(Error::CoverTextTooShort)),
+                _ => return Err(io_err(Error::InvalidUtf8)),
+            }
+        }
+
+        let mut inner = self.inner.take().expect("writer already finished");
+        inner.write_all(&self.pending)?;
+        self.pending.clear();
+        Ok(inner)
+    }
+}
+
+impl<W: Write> Write for Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.first_char_written {
+            return self.inner.as_mut().expect("writer already finished").write(buf);
+        }
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        self.pending.extend_from_slice(buf);
+
+        match std::str::from_utf8(&self.pending) {
+            Ok(s) => {
+                if let Some(first_char) = s.chars().next() {
+                    self.emit_first_char(first_char.len_utf8())?;
+                }
+            }
+            Err(e) if e.error_len().is_none() => {
+                // Incomplete multi-byte sequence at the end of what's been
+                // written so far; wait for more bytes before deciding.
+            }
+            Err(_) => {
+                // Not valid UTF-8 at all; treat the first raw byte as the
+                // boundary so we still make forward progress.
+                self.emit_first_char(1)?;
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.inner.as_mut() {
+            Some(inner) => inner.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Tracks how many zero-width bits have been folded into the current byte.
+#[derive(Default)]
+struct BitAccumulator {
+    current: u8,
+    count: u8,
+}
+
+impl BitAccumulator {
+    /// Folds in one bit, returning the completed byte once 8 have arrived.
+    fn push(&mut self, bit: u8) -> Option<u8> {
+        self.current = (self.current << 1) | bit;
+        self.count += 1;
+        if self.count == 8 {
+            let byte = self.current;
+            self.current = 0;
+            self.count = 0;
+            Some(byte)
+        } else {
+            None
+        }
+    }
+}
+
+/// Which part of the framed message the [`Reader`] is currently scanning.
+enum Phase {
+    /// Still looking for `START_MARKER`; cover text is ignored.
+    BeforeStart,
+    /// Between the markers, collecting zero-width bits.
+    InBody,
+    /// `END_MARKER` seen; no more payload bytes will arrive.
+    Done,
+}
+
+/// Streaming decoder that scans cover text for zero-width characters and
+/// yields the hidden secret incrementally via [`Read`], without buffering
+/// the whole cover text in memory.
+///
+/// Markers and bit-runs may straddle the chunk boundaries of the
+/// underlying reader; `Reader` buffers partial UTF-8 sequences and partial
+/// bit-runs between reads to handle this.
+///
+/// # Example
+///
+/// ```
+/// use std::io::{Read, Write};
+/// use whisper_text::{Reader, Writer};
+///
+/// let mut writer = Writer::new(Vec::new(), b"secret");
+/// writer.write_all(b"Hello, World!").unwrap();
+/// let encoded = writer.finish().unwrap();
+///
+/// let mut reader = Reader::new(encoded.as_slice());
+/// let mut secret = Vec::new();
+/// reader.read_to_end(&mut secret).unwrap();
+/// assert_eq!(secret, b"secret");
+/// ```
+pub struct Reader<R: Read> {
+    inner: R,
+    phase: Phase,
+    pending: Vec<u8>,
+    bits: BitAccumulator,
+    out_buf: Vec<u8>,
+    out_pos: usize,
+    eof: bool,
+}
+
+impl<R: Read> Reader<R> {
+    /// Creates a new streaming reader over cover text containing a hidden
+    /// message embedded by [`Writer`] or [`crate::encode`].
+    pub fn new(inner: R) -> Self {
+        Reader {
+            inner,
+            phase: Phase::BeforeStart,
+            pending: Vec::new(),
+            bits: BitAccumulator::default(),
+            out_buf: Vec::new(),
+            out_pos: 0,
+            eof: false,
+        }
+    }
+
+    /// Pulls more cover text from the inner reader until either some
+    /// decoded output is available or the end state is reached.
+    fn fill(&mut self) -> io::Result<()> {
+        let mut chunk = [0u8; CHUNK_SIZE];
+
+        while self.out_pos >= self.out_buf.len() && !matches!(self.phase, Phase::Done) {
+            if self.eof {
+                return match self.phase {
+                    // Mirrors `decode`, which also reports `NoHiddenMessage`
+                    // (rather than `CorruptedPayload`) when the end marker
+                    // is missing, regardless of whether the start marker
+                    // was found.
+                    Phase::BeforeStart | Phase::InBody => Err(io_err(Error::NoHiddenMessage)),
+                    Phase::Done => Ok(()),
+                };
+            }
+
+            let n = self.inner.read(&mut chunk)?;
+            if n == 0 {
+                self.eof = true;
+                continue;
+            }
+
+            self.pending.extend_from_slice(&chunk[..n]);
+            self.consume_pending()?;
+        }
+
+        Ok(())
+    }
+
+    /// Decodes as many complete characters as are currently available in
+    /// `self.pending`, leaving a trailing incomplete UTF-8 sequence (if
+    /// any) buffered for the next call.
+    fn consume_pending(&mut self) -> io::Result<()> {
+        let (valid, consumed_len) = match std::str::from_utf8(&self.pending) {
+            Ok(s) => (s, self.pending.len()),
+            Err(e) if e.error_len().is_none() => {
+                let valid_up_to = e.valid_up_to();
+                let s = std::str::from_utf8(&self.pending[..valid_up_to])
+                    .expect("prefix up to valid_up_to is valid UTF-8");
+                (s, valid_up_to)
+            }
+            Err(_) => return Err(io_err(Error::InvalidUtf8)),
+        };
+
+        let start_marker = START_MARKER.chars().next().expect("non-empty marker");
+        let end_marker = END_MARKER.chars().next().expect("non-empty marker");
+
+        for ch in valid.chars() {
+            if matches!(self.phase, Phase::Done) {
+                break;
+            }
+
+            if matches!(self.phase, Phase::BeforeStart) {
+                if ch == start_marker {
+                    self.phase = Phase::InBody;
+                }
+                continue;
+            }
+
+            if ch == end_marker {
+                if self.bits.count != 0 {
+                    return Err(io_err(Error::CorruptedPayload));
+                }
+                self.phase = Phase::Done;
+                continue;
+            }
+
+            match ch {
+                ZERO_BIT => {
+                    if let Some(byte) = self.bits.push(0) {
+                        self.out_buf.push(byte);
+                    }
+                }
+                ONE_BIT => {
+                    if let Some(byte) = self.bits.push(1) {
+                        self.out_buf.push(byte);
+                    }
+                }
+                _ => {} // visible cover text interleaved with the payload
+            }
+        }
+
+        self.pending.drain(..consumed_len);
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.out_pos >= self.out_buf.len() && !matches!(self.phase, Phase::Done) {
+            self.fill()?;
+        }
+
+        let available = &self.out_buf[self.out_pos..];
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.out_pos += n;
+
+        if self.out_pos == self.out_buf.len() {
+            self.out_buf.clear();
+            self.out_pos = 0;
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_via_writer(cover: &[u8], secret: &[u8]) -> Vec<u8> {
+        let mut writer = Writer::new(Vec::new(), secret);
+        writer.write_all(cover).unwrap();
+        writer.finish().unwrap()
+    }
+
+    fn decode_via_reader(encoded: &[u8]) -> io::Result<Vec<u8>> {
+        let mut reader = Reader::new(encoded);
+        let mut secret = Vec::new();
+        reader.read_to_end(&mut secret)?;
+        Ok(secret)
+    }
+
+    #[test]
+    fn test_round_trip_whole_buffer() {
+        let encoded = encode_via_writer(b"Hello, World!", b"secret");
+        let decoded = decode_via_reader(&encoded).unwrap();
+        assert_eq!(decoded, b"secret");
+    }
+
+    #[test]
+    fn test_round_trip_matches_codec_encode() {
+        let cover = "Hello, World!";
+        let secret = "secret";
+        let encoded = encode_via_writer(cover.as_bytes(), secret.as_bytes());
+        assert_eq!(encoded, crate::encode(cover, secret).unwrap().into_bytes());
+    }
+
+    #[test]
+    fn test_writer_chunked_across_marker() {
+        let mut writer = Writer::new(Vec::new(), b"hi");
+        // Feed the cover text one byte at a time, forcing the first-char
+        // boundary and every subsequent write through the byte-wise path.
+        for byte in b"Cover text that is long enough" {
+            writer.write_all(&[*byte]).unwrap();
+        }
+        let encoded = writer.finish().unwrap();
+        let decoded = decode_via_reader(&encoded).unwrap();
+        assert_eq!(decoded, b"hi");
+    }
+
+    #[test]
+    fn test_reader_chunked_one_byte_at_a_time() {
+        let encoded = encode_via_writer(b"Hello, World!", b"secret");
+
+        let mut reader = Reader::new(encoded.as_slice());
+        let mut secret = Vec::new();
+        let mut byte = [0u8; 1];
+        loop {
+            let n = reader.read(&mut byte).unwrap();
+            if n == 0 {
+                break;
+            }
+            secret.push(byte[0]);
+        }
+        assert_eq!(secret, b"secret");
+    }
+
+    #[test]
+    fn test_reader_no_hidden_message() {
+        let result = decode_via_reader(b"just plain text");
+        assert_eq!(
+            result.unwrap_err().into_inner().unwrap().to_string(),
+            Error::NoHiddenMessage.to_string()
+        );
+    }
+
+    #[test]
+    fn test_writer_empty_cover_errors() {
+        let writer = Writer::new(Vec::new(), b"secret");
+        let result = writer.finish();
+        assert_eq!(
+            result.unwrap_err().into_inner().unwrap().to_string(),
+            Error::CoverTextTooShort.to_string()
+        );
+    }
+
+    #[test]
+    fn test_round_trip_unicode_cover_and_secret() {
+        let cover = "Hello, 世界! 👋";
+        let secret = "Unicode: 你好 🚀";
+        let encoded = encode_via_writer(cover.as_bytes(), secret.as_bytes());
+        let decoded = decode_via_reader(&encoded).unwrap();
+        assert_eq!(decoded, secret.as_bytes());
+    }
+}